@@ -1,19 +1,112 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::fmt;
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::mem;
+use std::sync::{Arc, OnceLock};
+
+use colored::Colorize;
+use rayon::prelude::*;
 
 pub const DICTIONARY_FILE: &str = "dictionary.txt";
 pub const SOLUTIONS_FILE: &str = "solutions.txt";
 
-const WORD_LENGTH: u8 = 5;
-const NUM_GUESSES: u8 = 6;
+const DEFAULT_WORD_LENGTH: u8 = 5;
+const DEFAULT_NUM_GUESSES: u8 = 6;
 const LETTERS: [char; 26] = [
   'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
   'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
 ];
-const BEST_FIRST_GUESS: &str = "ROATE";
+const DEFAULT_OPENING_GUESS: &str = "ROATE";
+// Maximum number of candidate words listed by `_possible_solutions_summary`
+// before the rest are collapsed into a "(+K more)" suffix.
+const SOLUTIONS_SUMMARY_MAX_WORDS: usize = 10;
+
+/// Configures the word length and guess budget a `GameState` plays with, so
+/// the solver isn't locked to standard 5-letter / 6-guess English Wordle.
+#[derive(Clone, Debug)]
+pub struct GameConfig {
+  pub word_length: u8,
+  pub num_guesses: u8,
+  /// The guess to play first, skipping the information-gain search. If
+  /// `None`, the opening guess is derived by running the normal search
+  /// against the full solution set.
+  pub opening_guess: Option<String>,
+}
+
+impl Default for GameConfig {
+  fn default() -> GameConfig {
+    GameConfig {
+      word_length: DEFAULT_WORD_LENGTH,
+      num_guesses: DEFAULT_NUM_GUESSES,
+      opening_guess: Some(DEFAULT_OPENING_GUESS.to_string()),
+    }
+  }
+}
+
+/// Selects how `GameState::calculate_best_guess` searches the dictionary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchMode {
+  /// Evaluate every candidate guess on the current thread.
+  Serial,
+  /// Evaluate candidate guesses across a rayon thread pool.
+  Parallel,
+}
+
+/// A pluggable guessing strategy, so callers can compare how different
+/// approaches perform against the same dictionary and solutions.
+pub trait Solver: Sync {
+  fn choose_guess<'a>(&self, state: &'a GameState) -> &'a str;
+
+  /// Whether this solver needs `GameState`'s precomputed guess/solution
+  /// score matrix. Callers that simulate many games (e.g. [`benchmark`])
+  /// use this to avoid building that (potentially large) matrix at all for
+  /// solvers that never consult it.
+  fn needs_score_matrix(&self) -> bool {
+    false
+  }
+}
+
+/// The default strategy: greedily guesses the word with the highest
+/// information gain against the remaining possible solutions.
+pub struct InformationGainSolver {
+  pub search_mode: SearchMode,
+}
+
+impl Solver for InformationGainSolver {
+  fn choose_guess<'a>(&self, state: &'a GameState) -> &'a str {
+    state.calculate_best_guess(self.search_mode)
+  }
+
+  fn needs_score_matrix(&self) -> bool {
+    true
+  }
+}
+
+/// A "hard mode" strategy that never computes information gain: it opens with
+/// `GameConfig::opening_guess` when one is set, and otherwise plays the first
+/// dictionary word consistent with every constraint seen so far, which in
+/// particular rules out any word that reuses a letter already known to be
+/// Absent.
+pub struct NaiveSolver;
+
+impl Solver for NaiveSolver {
+  fn choose_guess<'a>(&self, state: &'a GameState) -> &'a str {
+    if state.is_initial_state {
+      if let Some(opening_guess) = &state.config.opening_guess {
+        return opening_guess;
+      }
+    }
+
+    state
+      .dictionary
+      .iter()
+      .find(|word| state._word_fits(word))
+      .map(|word| word.as_str())
+      .expect("no dictionary word satisfies the known constraints")
+  }
+}
 
 #[derive(Debug)]
 struct FrequencyPredicate {
@@ -62,41 +155,170 @@ impl FrequencyPredicate {
   }
 }
 
-struct GameState {
+// The precomputed guess/solution pattern matrix a `GameState` needs to run
+// `calculate_information_gain` without re-evaluating guesses. Building this
+// is `O(dictionary * solutions)`, so it's kept behind an `Arc` and shared
+// across every `GameState` simulating games against the same dictionary and
+// solutions (see `benchmark`) instead of being rebuilt per game.
+struct ScoreMatrix {
+  // scores[guess_idx][solution_idx] is the base-3 pattern code that guessing
+  // `dictionary[guess_idx]` produces against `solutions[solution_idx]`. `u16`
+  // comfortably covers every pattern code up to a 10-letter word
+  // (3^10 < u16::MAX), well beyond any realistic `GameConfig::word_length`.
+  scores: Vec<Vec<u16>>,
+  // Maps a solution word to its index into the solutions list the matrix was
+  // built against, i.e. the column index into `scores`.
+  solution_indices: HashMap<String, usize>,
+}
+
+impl ScoreMatrix {
+  fn build(dictionary: &[String], possible_solutions: &[String], word_length: u8) -> ScoreMatrix {
+    let pattern_weights = pattern_code_weights(word_length);
+    let scores = dictionary
+      .iter()
+      .map(|guess| {
+        possible_solutions
+          .iter()
+          .map(|solution| evaluate_guess(guess, solution).pattern_code(&pattern_weights) as u16)
+          .collect()
+      })
+      .collect();
+    let solution_indices = possible_solutions
+      .iter()
+      .enumerate()
+      .map(|(i, solution)| (solution.clone(), i))
+      .collect();
+
+    ScoreMatrix {
+      scores,
+      solution_indices,
+    }
+  }
+}
+
+pub struct GameState {
   // FIXME: Use a reference for dictionary
   dictionary: Vec<String>,
   possible_solutions: Vec<String>,
   is_initial_state: bool,
   letter_frequencies: HashMap<char, FrequencyPredicate>,
-  possible_letters: [HashSet<char>; (WORD_LENGTH as usize)],
+  possible_letters: Vec<HashSet<char>>,
+  // The guess/solution score matrix, built lazily on first use so solvers
+  // that never call `calculate_information_gain` (e.g. `NaiveSolver`) never
+  // pay to construct it. Can also be pre-populated via `new_with_scores` so
+  // many `GameState`s can share one matrix instead of each building their
+  // own.
+  scores: OnceLock<Arc<ScoreMatrix>>,
+  // The full solutions list this game started with, kept around so `undo`
+  // can rebuild state from scratch and replay `history` minus the undone
+  // guesses.
+  initial_solutions: Vec<String>,
+  // Every `GuessOutcome` applied via `update`, in order, used to support
+  // `undo`.
+  history: Vec<GuessOutcome>,
+  config: GameConfig,
 }
 
 impl GameState {
-  fn new(dictionary: &Vec<String>, possible_solutions: &Vec<String>) -> GameState {
+  fn new(dictionary: &Vec<String>, possible_solutions: &Vec<String>, config: GameConfig) -> GameState {
+    GameState::new_with_scores(dictionary, possible_solutions, config, None)
+  }
+
+  // Like `new`, but lets the caller supply an already-built score matrix
+  // (shared across many `GameState`s simulating games against the same
+  // dictionary and solutions, see `benchmark`) instead of building a fresh
+  // one lazily the first time this `GameState` needs it.
+  fn new_with_scores(
+    dictionary: &Vec<String>,
+    possible_solutions: &Vec<String>,
+    config: GameConfig,
+    scores: Option<Arc<ScoreMatrix>>,
+  ) -> GameState {
+    let word_length = config.word_length as usize;
+    for word in dictionary.iter().chain(possible_solutions.iter()) {
+      assert_eq!(
+        word.chars().count(),
+        word_length,
+        "word `{}` does not match configured word length {}",
+        word,
+        word_length
+      );
+    }
+
+    let scores_cell = OnceLock::new();
+    if let Some(scores) = scores {
+      scores_cell.set(scores).ok();
+    }
+
     GameState {
       dictionary: dictionary.clone(),
       possible_solutions: possible_solutions.clone(),
       is_initial_state: true,
       letter_frequencies: LETTERS.map(|l| (l, FrequencyPredicate::new())).into(),
-      possible_letters: [(); (WORD_LENGTH as usize)].map(|_| HashSet::from(LETTERS)),
+      possible_letters: vec![HashSet::from(LETTERS); word_length],
+      scores: scores_cell,
+      initial_solutions: possible_solutions.clone(),
+      history: Vec::new(),
+      config,
+    }
+  }
+
+  // Returns the score matrix, building it against the current
+  // `possible_solutions` on first use if one wasn't supplied up front.
+  // Building lazily from whatever `possible_solutions` holds at the time is
+  // sound because `possible_solutions` only ever shrinks over a `GameState`'s
+  // lifetime (via `_update_potential_solutions`), so any word present later
+  // was already present when the matrix was built.
+  fn score_matrix(&self) -> &ScoreMatrix {
+    self.scores.get_or_init(|| {
+      Arc::new(ScoreMatrix::build(
+        &self.dictionary,
+        &self.possible_solutions,
+        self.config.word_length,
+      ))
+    })
+  }
+
+  // Rolls back up to `n` applied guesses by rebuilding state from scratch and
+  // replaying the retained history. Returns the number of guesses actually
+  // undone (clamped to the size of the history).
+  fn undo(&mut self, n: usize) -> usize {
+    let undone = n.min(self.history.len());
+    let keep = self.history.len() - undone;
+    let replay = self.history[..keep].to_vec();
+
+    *self = GameState::new(&self.dictionary, &self.initial_solutions, self.config.clone());
+    for outcome in replay {
+      self.update(outcome);
     }
+
+    undone
   }
 
-  fn calculate_best_guess(&self) -> &str {
+  fn calculate_best_guess(&self, search_mode: SearchMode) -> &str {
     // println!("Calculating best guess..");
     if self.is_initial_state {
-      return BEST_FIRST_GUESS;
+      if let Some(opening_guess) = &self.config.opening_guess {
+        return opening_guess;
+      }
     }
 
     if self.possible_solutions.len() == 1 {
       return &self.possible_solutions[0];
     }
 
+    match search_mode {
+      SearchMode::Serial => self._calculate_best_guess_serial(),
+      SearchMode::Parallel => self._calculate_best_guess_parallel(),
+    }
+  }
+
+  fn _calculate_best_guess_serial(&self) -> &str {
     let mut best_word: Option<&str> = None;
     let mut max_gain: Option<f32> = None;
 
-    for word in &self.dictionary {
-      let gain = self.calculate_information_gain(word, &self.possible_solutions);
+    for (guess_idx, word) in self.dictionary.iter().enumerate() {
+      let gain = self.calculate_information_gain(guess_idx, &self.possible_solutions);
       let new_best = match max_gain {
         None => true,
         Some(mg) => gain > mg,
@@ -111,27 +333,51 @@ impl GameState {
     best_word.unwrap()
   }
 
-  fn calculate_information_gain(&self, word: &str, potential_solutions: &Vec<String>) -> f32 {
-    let mut outcomes = HashMap::with_capacity(potential_solutions.len());
+  fn _calculate_best_guess_parallel(&self) -> &str {
+    self
+      .dictionary
+      .par_iter()
+      .enumerate()
+      .map(|(guess_idx, word)| {
+        let gain = self.calculate_information_gain(guess_idx, &self.possible_solutions);
+        (gain, guess_idx, word.as_str())
+      })
+      .reduce_with(|a, b| {
+        // Tie-break on dictionary index (lowest wins), matching
+        // `_calculate_best_guess_serial`'s first-occurrence-wins behavior, so
+        // the two searches agree regardless of which thread finishes first or
+        // how the dictionary happens to be ordered.
+        if b.0 > a.0 || (b.0 == a.0 && b.1 < a.1) {
+          b
+        } else {
+          a
+        }
+      })
+      .unwrap()
+      .2
+  }
+
+  fn calculate_information_gain(&self, guess_idx: usize, potential_solutions: &Vec<String>) -> f32 {
+    let scores = self.score_matrix();
+    let mut counts = vec![0u32; num_pattern_codes(self.config.word_length)];
     for potential_solution in potential_solutions {
-      let outcome = evaluate_guess(word, potential_solution);
-      outcomes
-        .entry(outcome.letter_outcomes())
-        .and_modify(|n| *n += 1)
-        .or_insert(1);
+      let solution_idx = scores.solution_indices[potential_solution];
+      counts[scores.scores[guess_idx][solution_idx] as usize] += 1;
     }
 
     let total_potential_solutions: u32 = potential_solutions.len().try_into().unwrap();
-    outcomes
-      .values()
-      .map(|n| {
-        let outcome_probability = (*n as f32) / (total_potential_solutions as f32);
+    counts
+      .iter()
+      .filter(|&&n| n > 0)
+      .map(|&n| {
+        let outcome_probability = (n as f32) / (total_potential_solutions as f32);
         ((total_potential_solutions - n) as f32) * outcome_probability
       })
       .sum()
   }
 
   fn update(&mut self, guess: GuessOutcome) {
+    self.history.push(guess.clone());
     self._update_state(guess);
     self._update_potential_solutions();
   }
@@ -145,12 +391,24 @@ impl GameState {
   }
 
   fn _possible_solutions_summary(&self) -> String {
-    String::from("FIXME")
+    let mut sorted_solutions = self.possible_solutions.clone();
+    sorted_solutions.sort();
+
+    if sorted_solutions.len() <= SOLUTIONS_SUMMARY_MAX_WORDS {
+      return sorted_solutions.join(", ");
+    }
+
+    let shown = &sorted_solutions[..SOLUTIONS_SUMMARY_MAX_WORDS];
+    format!(
+      "{} (+{} more)",
+      shown.join(", "),
+      sorted_solutions.len() - SOLUTIONS_SUMMARY_MAX_WORDS
+    )
   }
 
   fn _update_state(&mut self, guess: GuessOutcome) {
     let mut info_by_letter: HashMap<char, Vec<(usize, LetterOutcome)>> =
-      HashMap::with_capacity(WORD_LENGTH.into());
+      HashMap::with_capacity(self.config.word_length.into());
     for (i, (letter, letter_outcome)) in guess.letters.into_iter().enumerate() {
       info_by_letter
         .entry(letter)
@@ -245,9 +503,28 @@ impl LetterOutcome {
       _ => panic!("Invalid letter outcome: {}", outcome_char),
     }
   }
+
+  fn code(self) -> u32 {
+    match self {
+      LetterOutcome::Absent => 0,
+      LetterOutcome::Present => 1,
+      LetterOutcome::Correct => 2,
+    }
+  }
 }
 
-#[derive(Debug)]
+// Weights used to fold a per-position outcome (Absent=0, Present=1, Correct=2)
+// into a single base-3 "pattern code", so a full guess/solution outcome can
+// be used as an array index instead of a hashed Vec<LetterOutcome>.
+fn pattern_code_weights(word_length: u8) -> Vec<u32> {
+  (0..word_length as u32).map(|i| 3u32.pow(i)).collect()
+}
+
+fn num_pattern_codes(word_length: u8) -> usize {
+  3usize.pow(word_length.into())
+}
+
+#[derive(Debug, Clone)]
 struct GuessOutcome {
   letters: Vec<(char, LetterOutcome)>,
 }
@@ -262,8 +539,16 @@ impl GuessOutcome {
     }
   }
 
-  fn letter_outcomes(&self) -> Vec<LetterOutcome> {
-    self.letters.iter().map(|l| l.1).collect()
+  // Packs the per-position outcomes into a base-3 code using `weights`
+  // (one weight per letter position) so the outcome can be used directly as
+  // an array index.
+  fn pattern_code(&self, weights: &[u32]) -> u32 {
+    self
+      .letters
+      .iter()
+      .zip(weights.iter())
+      .map(|((_, outcome), weight)| outcome.code() * weight)
+      .sum()
   }
 
   fn is_win(&self) -> bool {
@@ -274,11 +559,30 @@ impl GuessOutcome {
   }
 }
 
+impl fmt::Display for GuessOutcome {
+  // Renders the guess as a row of colored letter tiles, reproducing the
+  // familiar Wordle grid: green background for Correct, yellow for Present,
+  // and a neutral dark background for Absent.
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    for (letter, outcome) in &self.letters {
+      let tile = format!(" {} ", letter);
+      let rendered = match outcome {
+        LetterOutcome::Correct => tile.black().on_green(),
+        LetterOutcome::Present => tile.black().on_yellow(),
+        LetterOutcome::Absent => tile.white().on_black(),
+      };
+      write!(f, "{}", rendered)?;
+    }
+    Ok(())
+  }
+}
+
 fn evaluate_guess(guess: &str, solution: &str) -> GuessOutcome {
-  let mut results = vec![LetterOutcome::Absent; NUM_GUESSES.into()];
+  let word_length = guess.chars().count();
+  let mut results = vec![LetterOutcome::Absent; word_length];
 
   // Track whether each solution letter has been accounted for in the results.
-  let mut accounted = vec![false; WORD_LENGTH.into()];
+  let mut accounted = vec![false; word_length];
 
   // First, mark all letters that are exactly correct
   for (i, (guess_letter, solution_letter)) in guess.chars().zip(solution.chars()).enumerate() {
@@ -324,26 +628,185 @@ pub fn load_words_file(path: &str) -> io::Result<Vec<String>> {
 pub fn play_wordle(
   dictionary: &Vec<String>,
   possible_solutions: &Vec<String>,
+  solver: &dyn Solver,
+  config: GameConfig,
 ) -> Result<(), Box<dyn Error>> {
-  let mut game_state = GameState::new(dictionary, possible_solutions);
+  let num_guesses = config.num_guesses;
+  let mut game_state = GameState::new(dictionary, possible_solutions, config);
 
-  for i in 0..NUM_GUESSES {
+  let mut i = 0;
+  while i < num_guesses {
     println!("Playing guess: {}", i);
-    let suggested_guess = game_state.calculate_best_guess();
-    let outcome = prompt_guess(suggested_guess);
-    if outcome.is_win() {
-      println!("You win!");
-      break;
+    let suggested_guess = solver.choose_guess(&game_state);
+    match prompt_guess(suggested_guess) {
+      PlayerAction::Undo(n) => {
+        let undone = game_state.undo(n);
+        println!("Undid {} guess(es).", undone);
+        i -= (undone as u8).min(i);
+      }
+      PlayerAction::Outcome(outcome) => {
+        println!("{}", outcome);
+        if outcome.is_win() {
+          println!("You win!");
+          break;
+        }
+        game_state.update(outcome);
+        println!("{}", game_state.summary());
+        i += 1;
+      }
     }
-    game_state.update(outcome);
-    println!("{}", game_state.summary());
   }
   Ok(())
 }
 
-fn prompt_guess(guess: &str) -> GuessOutcome {
+// Plays out a full game against `solution_word` using `solver`, returning the
+// number of guesses it took to win, or `None` if it wasn't solved within
+// `config.num_guesses`. `scores`, if supplied, is shared with every other
+// `simulate_game` call in the same batch (see `benchmark`) instead of each
+// one building its own score matrix.
+fn simulate_game(
+  dictionary: &Vec<String>,
+  possible_solutions: &Vec<String>,
+  solution_word: &str,
+  solver: &dyn Solver,
+  config: GameConfig,
+  scores: Option<Arc<ScoreMatrix>>,
+) -> Option<u8> {
+  let num_guesses = config.num_guesses;
+  let mut state = GameState::new_with_scores(dictionary, possible_solutions, config, scores);
+
+  for guess_num in 1..num_guesses + 1 {
+    let guess = solver.choose_guess(&state);
+    let outcome = evaluate_guess(guess, solution_word);
+    if outcome.is_win() {
+      return Some(guess_num);
+    }
+    state.update(outcome);
+  }
+
+  None // If we get here, we didn't find a solution in time.
+}
+
+/// Summary statistics produced by [`benchmark`] for a solver run across every
+/// possible solution.
+#[derive(Debug)]
+pub struct BenchmarkReport {
+  /// `histogram[n - 1]` is the number of solutions solved in exactly `n`
+  /// guesses; the last bucket counts solutions that were not solved within
+  /// the guess budget.
+  pub histogram: Vec<u32>,
+  pub win_rate: f32,
+  /// Mean, median, and worst-case guess counts across every attempted
+  /// solution. A solution that wasn't solved within the guess budget counts
+  /// as `num_guesses + 1` guesses, so a solver that fails on hard words can't
+  /// improve these numbers by failing instead of just barely succeeding.
+  pub mean_guesses: f32,
+  pub median_guesses: f32,
+  pub worst_case_guesses: Option<u8>,
+  /// Solution words that were not solved within the guess budget.
+  pub unsolved_words: Vec<String>,
+}
+
+/// Runs `solver` against every word in `possible_solutions` and reports
+/// aggregate performance, so a solver or opening word can be scored and
+/// compared reproducibly.
+pub fn benchmark(
+  dictionary: &Vec<String>,
+  possible_solutions: &Vec<String>,
+  solver: &dyn Solver,
+  config: GameConfig,
+  search_mode: SearchMode,
+) -> BenchmarkReport {
+  let num_guesses = config.num_guesses;
+
+  // Build the score matrix (if the solver needs one) once and share it
+  // across every simulated game in this batch, instead of each of the
+  // `possible_solutions.len()` games building its own — see `ScoreMatrix`.
+  let scores = solver
+    .needs_score_matrix()
+    .then(|| Arc::new(ScoreMatrix::build(dictionary, possible_solutions, config.word_length)));
+
+  let simulate = |solution_word: &String| {
+    (
+      solution_word.clone(),
+      simulate_game(
+        dictionary,
+        possible_solutions,
+        solution_word,
+        solver,
+        config.clone(),
+        scores.clone(),
+      ),
+    )
+  };
+  let results: Vec<(String, Option<u8>)> = match search_mode {
+    SearchMode::Serial => possible_solutions.iter().map(simulate).collect(),
+    SearchMode::Parallel => possible_solutions.par_iter().map(simulate).collect(),
+  };
+
+  let mut histogram = vec![0u32; (num_guesses as usize) + 1];
+  // Guess counts for every attempted solution, solved or not, so the
+  // mean/median/worst-case stats below can't be skewed by unsolved words
+  // dropping out of them. An unsolved word counts as `num_guesses + 1`.
+  let mut guess_counts: Vec<u8> = Vec::with_capacity(results.len());
+  let mut unsolved_words = Vec::new();
+  for (solution_word, outcome) in results {
+    match outcome {
+      Some(n) => {
+        histogram[(n - 1) as usize] += 1;
+        guess_counts.push(n);
+      }
+      None => {
+        histogram[num_guesses as usize] += 1;
+        guess_counts.push(num_guesses + 1);
+        unsolved_words.push(solution_word);
+      }
+    }
+  }
+
+  let total_solutions = possible_solutions.len() as f32;
+  let win_rate = 1.0 - (unsolved_words.len() as f32 / total_solutions);
+  let mean_guesses = if guess_counts.is_empty() {
+    0.0
+  } else {
+    guess_counts.iter().map(|&n| n as f32).sum::<f32>() / guess_counts.len() as f32
+  };
+  let median_guesses = median(&guess_counts);
+  let worst_case_guesses = guess_counts.iter().max().copied();
+
+  BenchmarkReport {
+    histogram,
+    win_rate,
+    mean_guesses,
+    median_guesses,
+    worst_case_guesses,
+    unsolved_words,
+  }
+}
+
+fn median(sorted_source: &[u8]) -> f32 {
+  if sorted_source.is_empty() {
+    return 0.0;
+  }
+  let mut values = sorted_source.to_vec();
+  values.sort_unstable();
+  let mid = values.len() / 2;
+  if values.len().is_multiple_of(2) {
+    (values[mid - 1] as f32 + values[mid] as f32) / 2.0
+  } else {
+    values[mid] as f32
+  }
+}
+
+// What the player asked for after being prompted for a guess's outcome.
+enum PlayerAction {
+  Outcome(GuessOutcome),
+  Undo(usize),
+}
+
+fn prompt_guess(guess: &str) -> PlayerAction {
   println!("Enter guess: `{}`", guess);
-  let mut current_guess = guess;
+  let current_guess = guess;
   // loop {
   println!(
     "Enter the outcome for guess `{}`, encoding each letter according to its color:
@@ -351,18 +814,31 @@ fn prompt_guess(guess: &str) -> GuessOutcome {
   Y = yellow
   G = green
 
-If you used a different guess than `{}`, enter it instead.",
+If you used a different guess than `{}`, enter it instead.
+Enter `undo` (optionally followed by a count, e.g. `undo 2`) to take back prior guesses.",
     current_guess, current_guess
   );
   let mut input = String::new();
   io::stdin().read_line(&mut input).unwrap();
   let outcome_str = input.trim();
   println!("Outcome string: {}", outcome_str);
+
+  if let Some(count_str) = outcome_str.strip_prefix("undo").map(|rest| rest.trim()) {
+    let count = if count_str.is_empty() {
+      1
+    } else {
+      count_str
+        .parse()
+        .unwrap_or_else(|_| panic!("Invalid undo count: {}", count_str))
+    };
+    return PlayerAction::Undo(count);
+  }
+
   if outcome_str
     .chars()
     .all(|c| c == 'B' || c == 'Y' || c == 'G')
   {
-    return GuessOutcome::parse(guess, outcome_str);
+    return PlayerAction::Outcome(GuessOutcome::parse(guess, outcome_str));
   }
 
   // // Something other than an outcome was entered.. should be a different guess.
@@ -379,36 +855,24 @@ If you used a different guess than `{}`, enter it instead.",
 mod tests {
   use super::*;
 
-  fn simulate_game(
-    dictionary: &Vec<String>,
-    possible_solutions: &Vec<String>,
-    solution_word: &str,
-  ) -> Option<u8> {
-    let mut state = GameState::new(dictionary, possible_solutions);
-
-    println!("Simulating wordle game for solution: {}", solution_word);
-    for guess_num in 1..NUM_GUESSES + 1 {
-      let guess = state.calculate_best_guess();
-      let outcome = evaluate_guess(guess, solution_word);
-      // println!("Guess #{}: {}; Outcome: {:#?}", guess_num, guess, outcome);
-
-      if outcome.is_win() {
-        return Some(guess_num);
-      }
-      state.update(outcome);
-    }
-
-    None // If we get here, we didn't find a solution in time.
-  }
-
   #[test]
   fn sample_game() {
     let dictionary = load_words_file(DICTIONARY_FILE).unwrap();
     let possible_solutions = load_words_file(SOLUTIONS_FILE).unwrap();
     let solution_word = "ABATE";
+    let solver = InformationGainSolver {
+      search_mode: SearchMode::Serial,
+    };
 
     // Should not panic
-    let num_guesses = simulate_game(&dictionary, &possible_solutions, solution_word);
+    let num_guesses = simulate_game(
+      &dictionary,
+      &possible_solutions,
+      solution_word,
+      &solver,
+      GameConfig::default(),
+      None,
+    );
 
     assert!(matches!(num_guesses, Some(_)));
     println!("Found solution in {} guesses.", num_guesses.unwrap());
@@ -419,13 +883,73 @@ mod tests {
     let dictionary = load_words_file(DICTIONARY_FILE).unwrap();
     let possible_solutions = load_words_file(SOLUTIONS_FILE).unwrap();
 
-    let mut histogram = [0; 7];
-    for solution_word in possible_solutions.iter() {
-      let num_guesses = simulate_game(&dictionary, &possible_solutions, solution_word);
-      println!("Num guesses for {}: {:?}", solution_word, num_guesses);
-      histogram[(num_guesses.unwrap_or(7) as usize) - 1] += 1;
+    let strategies: Vec<(&str, Box<dyn Solver>)> = vec![
+      (
+        "information_gain",
+        Box::new(InformationGainSolver {
+          search_mode: SearchMode::Parallel,
+        }),
+      ),
+      ("naive", Box::new(NaiveSolver)),
+    ];
+
+    for (name, solver) in strategies {
+      let report = benchmark(
+        &dictionary,
+        &possible_solutions,
+        solver.as_ref(),
+        GameConfig::default(),
+        SearchMode::Parallel,
+      );
+
+      println!(
+        "[{}] histogram: {:?}, win rate: {:.1}%, mean guesses: {:.2}, median guesses: {:.1}, worst case: {:?}, unsolved: {}",
+        name,
+        report.histogram,
+        report.win_rate * 100.0,
+        report.mean_guesses,
+        report.median_guesses,
+        report.worst_case_guesses,
+        report.unsolved_words.len()
+      );
+
+      assert!(report.win_rate > 0.0);
     }
+  }
 
-    println!("Guesses histogram: {:?}", histogram);
+  #[test]
+  fn serial_and_parallel_search_agree() {
+    let dictionary = load_words_file(DICTIONARY_FILE).unwrap();
+    let possible_solutions = load_words_file(SOLUTIONS_FILE).unwrap();
+    let config = GameConfig::default();
+
+    // Compare every turn's guess, not just whether the games finish in the
+    // same number of guesses: two different guess sequences can still land
+    // on the same final guess count, which would let a tie-break mismatch
+    // slip through undetected.
+    for solution_word in possible_solutions.iter() {
+      let mut serial_state = GameState::new(&dictionary, &possible_solutions, config.clone());
+      let mut parallel_state = GameState::new(&dictionary, &possible_solutions, config.clone());
+
+      for _ in 0..config.num_guesses {
+        let serial_guess = serial_state.calculate_best_guess(SearchMode::Serial).to_string();
+        let parallel_guess = parallel_state
+          .calculate_best_guess(SearchMode::Parallel)
+          .to_string();
+        assert_eq!(
+          serial_guess, parallel_guess,
+          "serial and parallel search disagreed when solving for `{}`",
+          solution_word
+        );
+
+        let outcome = evaluate_guess(&serial_guess, solution_word);
+        let is_win = outcome.is_win();
+        serial_state.update(outcome.clone());
+        parallel_state.update(outcome);
+        if is_win {
+          break;
+        }
+      }
+    }
   }
 }
@@ -4,5 +4,13 @@ fn main() -> Result<(), Box<dyn Error>> {
     let dictionary = wordle_solver::load_words_file(wordle_solver::DICTIONARY_FILE)?;
     let possible_solutions = wordle_solver::load_words_file(wordle_solver::SOLUTIONS_FILE)?;
 
-    wordle_solver::play_wordle(&dictionary, &possible_solutions)
+    let solver = wordle_solver::InformationGainSolver {
+        search_mode: wordle_solver::SearchMode::Parallel,
+    };
+    wordle_solver::play_wordle(
+        &dictionary,
+        &possible_solutions,
+        &solver,
+        wordle_solver::GameConfig::default(),
+    )
 }